@@ -1,8 +1,24 @@
 use iced::Length;
 use iced::font;
+use iced::mouse;
 use iced::time::{Duration, hours, minutes};
-use iced::widget::{button, center_x, center_y, column, container, row, scrollable, slider, text, tooltip};
-use iced::{Center, Element, Fill, Font};
+use iced::widget::{button, center_x, center_y, column, container, row, scrollable, stack, text, text_input};
+use iced::{Element, Fill, Font, Point};
+
+// Approximate row geometry, used to hit-test right clicks against rendered rows. Kept in sync
+// with the layout built in `view`: the filter input (plus its padding), the column header row,
+// then one row per event, each `TABLE_ROW_H` tall.
+const FILTER_ROW_H: f32 = 56.0;
+const TABLE_HEADER_H: f32 = 36.0;
+const TABLE_ROW_H: f32 = 36.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Name,
+    Time,
+    Price,
+    Rating,
+}
 
 #[derive(Debug, Clone)]
 pub enum TableMessage {
@@ -11,6 +27,9 @@ pub enum TableMessage {
     ShowDetails(usize),
     HideDetails,
     HideContext,
+    ContextMenu(usize, f32, f32),
+    SortBy(Column),
+    FilterChanged(String),
 }
 
 #[derive(Debug, Clone)]
@@ -19,8 +38,10 @@ pub struct Table {
     padding: (f32, f32),
     separator: (f32, f32),
     selected: Option<usize>,
-    last_cursor: Option<(f32, f32)>,
+    last_cursor: Option<Point>,
     context_menu: Option<(usize, f32, f32)>,
+    sort: Option<(Column, bool)>,
+    filter: String,
 }
 
 impl Table {
@@ -36,59 +57,108 @@ impl Table {
             TableMessage::ShowDetails(idx) => self.selected = Some(idx),
             TableMessage::HideDetails => self.selected = None,
             TableMessage::HideContext => self.context_menu = None,
+            TableMessage::ContextMenu(idx, x, y) => {
+                self.context_menu = Some((idx, x, y));
+            }
+            TableMessage::SortBy(col) => {
+                self.sort = Some(match self.sort {
+                    Some((current, ascending)) if current == col => (col, !ascending),
+                    _ => (col, true),
+                });
+            }
+            TableMessage::FilterChanged(filter) => self.filter = filter,
         }
     }
 
-    // Handle window events forwarded from main as a debug string. This is a heuristic
-    // approach: we parse debug output to extract cursor positions and right-click presses.
-    pub fn on_window_event_debug(&mut self, debug: &str) {
-        // update cursor position from debug strings that include moved/cursor coordinates
-        // different Iced/backends may produce slightly different Debug representations
-        // so we look for either "CursorMoved" or "Moved" and parse x/y floats if present.
-        if debug.contains("CursorMoved") || debug.contains("Moved(") || debug.contains("MovedPoint") {
-            // try to parse any "x: <float>" and "y: <float>" occurrences
-            let mut x_opt: Option<f32> = None;
-            let mut y_opt: Option<f32> = None;
-
-            if let Some(x_idx) = debug.find("x:") {
-                let tail = &debug[x_idx + 2..].trim_start();
-                let num_str: String = tail.chars().take_while(|c| c.is_ascii_digit() || *c == '.' || *c == '-').collect();
-                if let Ok(xv) = num_str.trim().parse::<f32>() {
-                    x_opt = Some(xv);
-                }
-            }
+    /// Name and price of the event at `idx`, for callers that only need a summary.
+    pub fn event_summary(&self, idx: usize) -> Option<(&str, f32)> {
+        self.events.get(idx).map(|event| (event.name.as_str(), event.price))
+    }
 
-            if let Some(y_idx) = debug.find("y:") {
-                let tail = &debug[y_idx + 2..].trim_start();
-                let num_str: String = tail.chars().take_while(|c| c.is_ascii_digit() || *c == '.' || *c == '-').collect();
-                if let Ok(yv) = num_str.trim().parse::<f32>() {
-                    y_opt = Some(yv);
-                }
-            }
+    pub fn padding(&self) -> (f32, f32) {
+        self.padding
+    }
 
-            if let (Some(xv), Some(yv)) = (x_opt, y_opt) {
-                self.last_cursor = Some((xv, yv));
-                log::debug!("Cursor updated to: {:?}", self.last_cursor);
-            }
+    pub fn separator(&self) -> (f32, f32) {
+        self.separator
+    }
+
+    /// Render the padding/separator controls, for hosting outside of `view` (e.g. a Settings tab).
+    pub fn settings_view(&self) -> Element<'_, TableMessage> {
+        crate::settings::view(self.padding, self.separator)
+    }
+
+    pub fn stats(&self) -> Stats {
+        let count = self.events.len();
+        let total_price = self.events.iter().map(|event| event.price).sum();
+        let average_rating = if count == 0 {
+            0.0
+        } else {
+            self.events.iter().map(|event| event.rating).sum::<f32>() / count as f32
+        };
+        let free_count = self.events.iter().filter(|event| event.price <= 0.0).count();
+
+        Stats {
+            count,
+            average_rating,
+            total_price,
+            free_count,
+            paid_count: count - free_count,
         }
+    }
 
-        // detect right-click press in debug string
-        // detect right-click press in debug string. Try several keywords that vary by backend
-        if (debug.contains("MouseInput") || debug.contains("MouseButton") || debug.contains("ButtonPressed") || debug.contains("Pressed"))
-            && debug.contains("Right")
-        {
-            // use last_cursor to compute row index
-            if let Some((x, y)) = self.last_cursor {
-                let header_h = 36.0;
-                let row_h = 36.0;
-                if y > header_h {
-                    let idx = ((y - header_h) / row_h).floor() as usize;
-                    if idx < self.events.len() {
-                        log::debug!("Context menu set for idx {} at ({},{})", idx, x, y);
-                        self.context_menu = Some((idx, x, y));
-                    }
-                }
-            }
+    /// Indices into `events`, filtered by name and ordered per the active sort column.
+    fn visible_indices(&self) -> Vec<usize> {
+        let needle = self.filter.to_lowercase();
+        let mut indices: Vec<usize> = self
+            .events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| needle.is_empty() || event.name.to_lowercase().contains(&needle))
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some((col, ascending)) = self.sort {
+            indices.sort_by(|&a, &b| {
+                let ordering = match col {
+                    Column::Name => self.events[a].name.cmp(&self.events[b].name),
+                    Column::Time => self.events[a].duration.cmp(&self.events[b].duration),
+                    Column::Price => self.events[a].price.total_cmp(&self.events[b].price),
+                    Column::Rating => self.events[a].rating.total_cmp(&self.events[b].rating),
+                };
+                if ascending { ordering } else { ordering.reverse() }
+            });
+        }
+
+        indices
+    }
+
+    pub fn on_cursor_moved(&mut self, position: Point) {
+        self.last_cursor = Some(position);
+    }
+
+    /// Hit-test a right click against the rendered rows.
+    ///
+    /// `chrome_above` is the height of whatever the caller stacks above this widget (e.g. a tab
+    /// bar) in window coordinates, since `last_cursor` is a window-absolute position and this
+    /// view has no access to iced's layout tree to measure that itself. The menu is stored (and
+    /// later rendered) in this widget's own local coordinates, i.e. with `chrome_above` already
+    /// subtracted out, since `view` only ever positions it within its own `stack`.
+    pub fn on_mouse_pressed(&mut self, button: mouse::Button, chrome_above: f32) {
+        if button != mouse::Button::Right {
+            return;
+        }
+        let Some(pos) = self.last_cursor else { return };
+        let local_y = pos.y - chrome_above;
+        let header_h = FILTER_ROW_H + TABLE_HEADER_H;
+        let row_h = TABLE_ROW_H;
+        if local_y <= header_h {
+            return;
+        }
+        let row = ((local_y - header_h) / row_h).floor() as usize;
+        let visible = self.visible_indices();
+        if let Some(&idx) = visible.get(row) {
+            self.update(TableMessage::ContextMenu(idx, pos.x, local_y));
         }
     }
 
@@ -101,12 +171,34 @@ impl Table {
             })
         };
 
+        let header_cell = |label: &'static str, col: Column, width: u16| {
+            let indicator = match self.sort {
+                Some((current, ascending)) if current == col => {
+                    if ascending {
+                        " \u{2191}"
+                    } else {
+                        " \u{2193}"
+                    }
+                }
+                _ => "",
+            };
+            let label_text = text(format!("{label}{indicator}")).font(Font {
+                weight: font::Weight::Bold,
+                ..Font::DEFAULT
+            });
+            button(label_text).on_press(TableMessage::SortBy(col)).style(button::text).width(width)
+        };
+
+        let filter_input = text_input("Filter by name...", &self.filter)
+            .on_input(TableMessage::FilterChanged)
+            .width(300);
+
         let mut rows = column![
             row![
-                bold("Name").width(300),
-                bold("Time").width(80),
-                bold("Price").width(80),
-                bold("Rating").width(80),
+                header_cell("Name", Column::Name, 300),
+                header_cell("Time", Column::Time, 80),
+                header_cell("Price", Column::Price, 80),
+                header_cell("Rating", Column::Rating, 80),
                 // actions
                 bold("")
             ]
@@ -114,7 +206,8 @@ impl Table {
             .padding(5)
         ];
 
-        for (i, event) in self.events.iter().enumerate() {
+        for i in self.visible_indices() {
+            let event = &self.events[i];
             let minutes = event.duration.as_secs() / 60;
             let time_text = text(format!("{minutes} min")).style(if minutes > 90 { text::warning } else { text::default });
             let price_text = if event.price > 0.0 {
@@ -145,43 +238,17 @@ impl Table {
             );
         }
 
-        let controls = {
-            let labeled_slider = |label, range: std::ops::RangeInclusive<f32>, (x, y), on_change: fn(f32, f32) -> TableMessage| {
-                row![
-                    text(label).font(Font::MONOSPACE).size(14).width(100),
-                    tooltip(
-                        slider(range.clone(), x, move |x| on_change(x, y)),
-                        text!("{x:.0}px").font(Font::MONOSPACE).size(10),
-                        tooltip::Position::Left
-                    ),
-                    tooltip(
-                        slider(range, y, move |y| on_change(x, y)),
-                        text!("{y:.0}px").font(Font::MONOSPACE).size(10),
-                        tooltip::Position::Right
-                    ),
-                ]
-                .spacing(10)
-                .align_y(Center)
-            };
-
-            column![
-                labeled_slider("Padding", 0.0..=30.0, self.padding, TableMessage::PaddingChanged),
-                labeled_slider("Separator", 0.0..=5.0, self.separator, TableMessage::SeparatorChanged)
-            ]
-            .spacing(10)
-            .width(400)
-        };
-
-        // Compose main column: table rows + controls
-        let mut main_col = column![
+        // Compose main column: filter + table rows
+        let main_col = column![
+            center_x(filter_input).padding(10),
             center_y(scrollable(center_x(rows)).spacing(10)).padding(10),
-            center_x(controls).padding(10).style(container::dark)
         ]
         .spacing(10);
 
-        // Render context menu if requested
-        // Render context menu if requested. We try to position it near last_cursor when possible.
-        if let Some((idx, x, _y)) = self.context_menu {
+        let mut layers: Vec<Element<'_, TableMessage>> = vec![main_col.into()];
+
+        // Layer the context menu on top, positioned at the cursor that triggered it.
+        if let Some((idx, x, y)) = self.context_menu {
             let menu = container(column![
                 button(text("Show details")).on_press(TableMessage::ShowDetails(idx)),
                 button(text("Close menu")).on_press(TableMessage::HideContext)
@@ -190,20 +257,16 @@ impl Table {
             .style(container::dark)
             .width(200);
 
-            // approximate horizontal position: if x is known and large use left padding to shift menu
-            let positioned = if x > 0.0 {
-                // convert x into a left padding amount (clamped)
-                let pad = (x - 100.0).clamp(0.0, 600.0) as u16;
-                container(menu).padding(pad)
-            } else {
-                container(menu)
-            };
+            let positioned = container(menu).padding(iced::Padding {
+                top: y,
+                left: x,
+                ..iced::Padding::ZERO
+            });
 
-            main_col = main_col.push(positioned.padding(10));
+            layers.push(positioned.into());
         }
 
-        // Render modal dialog when a row is selected
-        // If modal is active, render it as a full-screen overlay so it behaves like a blocking modal.
+        // Layer the details modal on top, centered over a dimmed backdrop.
         if let Some(idx) = self.selected
             && let Some(ev) = self.events.get(idx)
         {
@@ -218,17 +281,15 @@ impl Table {
             .width(400)
             .style(container::dark);
 
-            // full-screen semi-transparent backdrop + centered modal body
-            let backdrop = container(column![center_y(center_x(modal_body))])
+            let backdrop = container(center_y(center_x(modal_body)))
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .style(container::dark);
 
-            // Return only the overlay to ensure it visually blocks the rest of the UI.
-            return backdrop.into();
+            layers.push(backdrop.into());
         }
 
-        main_col.into()
+        stack(layers).into()
     }
 }
 
@@ -241,10 +302,81 @@ impl Default for Table {
             selected: None,
             last_cursor: None,
             context_menu: None,
+            sort: None,
+            filter: String::new(),
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_indices_with_no_filter_or_sort_is_identity() {
+        let table = Table::default();
+        let indices: Vec<usize> = (0..table.events.len()).collect();
+        assert_eq!(table.visible_indices(), indices);
+    }
+
+    #[test]
+    fn visible_indices_filters_by_name_substring_case_insensitively() {
+        let mut table = Table::default();
+        table.filter = "muni".to_owned();
+        let visible = table.visible_indices();
+        assert_eq!(visible.len(), 1);
+        assert!(table.events[visible[0]].name.contains("MUNI"));
+    }
+
+    #[test]
+    fn visible_indices_sorts_by_price_and_toggles_direction() {
+        let mut table = Table::default();
+        table.update(TableMessage::SortBy(Column::Price));
+        let ascending = table.visible_indices();
+        let prices: Vec<f32> = ascending.iter().map(|&i| table.events[i].price).collect();
+        assert!(prices.windows(2).all(|w| w[0] <= w[1]));
+
+        table.update(TableMessage::SortBy(Column::Price));
+        let descending = table.visible_indices();
+        let prices: Vec<f32> = descending.iter().map(|&i| table.events[i].price).collect();
+        assert!(prices.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn visible_indices_combine_filter_and_sort_and_still_resolve_to_the_right_event() {
+        let mut table = Table::default();
+        table.filter = "a".to_owned();
+        table.sort = Some((Column::Rating, true));
+        let visible = table.visible_indices();
+
+        assert!(visible.iter().all(|&i| table.events[i].name.to_lowercase().contains('a')));
+        let ratings: Vec<f32> = visible.iter().map(|&i| table.events[i].rating).collect();
+        assert!(ratings.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn stats_reports_totals_and_free_vs_paid_breakdown() {
+        let table = Table::default();
+        let stats = table.stats();
+
+        assert_eq!(stats.count, table.events.len());
+        assert_eq!(stats.free_count + stats.paid_count, stats.count);
+        assert_eq!(stats.free_count, table.events.iter().filter(|event| event.price <= 0.0).count());
+
+        let expected_total: f32 = table.events.iter().map(|event| event.price).sum();
+        assert!((stats.total_price - expected_total).abs() < f32::EPSILON);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stats {
+    pub count: usize,
+    pub average_rating: f32,
+    pub total_price: f32,
+    pub free_count: usize,
+    pub paid_count: usize,
+}
+
 #[derive(Debug, Clone)]
 struct Event {
     name: String,
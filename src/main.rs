@@ -1,6 +1,8 @@
 use iced::{
-    Length, Theme,
-    widget::{button, column, container, row, text},
+    Task, Theme,
+    keyboard::{self, key::Named},
+    mouse,
+    widget::{column, stack},
     window,
 };
 use std::{
@@ -10,69 +12,187 @@ use std::{
 
 mod common_assets;
 mod data_table;
+mod settings;
+mod stats;
+mod tab_bar;
+mod toast;
 
 pub(crate) type BoxedError = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 pub(crate) const APP_NAME: &str = "MyApp";
 
-#[derive(Debug, Default, Clone)]
+const TOAST_TIMEOUT_SECS: f32 = 5.0;
+const TAB_LABELS: [&str; 3] = ["Events", "Stats", "Settings"];
+// Approximate rendered height of `tab_bar::view`'s row (button height plus its padding), used to
+// offset the table's own row hit-test since it only sees a window-absolute cursor position.
+const TAB_BAR_H: f32 = 56.0;
+
+#[derive(Debug, Clone, Default)]
 struct AppState {
-    show_confirm: bool,
+    window_id: Option<window::Id>,
+    is_active: bool,
     main_table: data_table::Table,
+    toasts: Vec<toast::Toast>,
+    active_tab: usize,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
-    WindowEvent(window::Event),
+    WindowEvent(window::Id, window::Event),
+    CursorMoved(iced::Point),
+    MousePressed(mouse::Button),
 
     TrayIconEvent(tray_icon::menu::MenuId),
-    ConfirmExit,
-    CancelExit,
     TbMsg(data_table::TableMessage),
+    PushToast(toast::Toast),
+    CloseToast(usize),
+    ExpireToasts,
+    TabSelected(usize),
+    TabNext,
+    TabPrevious,
     Noop,
 }
 
-fn update(state: &mut AppState, message: Message) {
+fn push_toast(state: &mut AppState, toast: toast::Toast) {
+    state.toasts.push(toast);
+}
+
+enum TrayAction {
+    Show,
+    Quit,
+}
+
+fn classify_tray_event(event_id: &tray_icon::menu::MenuId) -> Option<TrayAction> {
+    log::info!("Event ID: {event_id:?}");
+    let ids = TRAY_ICON_MENU_ITEM_IDS.lock().unwrap();
+    if ids.get(&STR_SHOW).is_some_and(|id| id == event_id) {
+        Some(TrayAction::Show)
+    } else if ids.get(&STR_QUIT).is_some_and(|id| id == event_id) {
+        Some(TrayAction::Quit)
+    } else {
+        None
+    }
+}
+
+fn update(state: &mut AppState, message: Message) -> Task<Message> {
     match message {
-        Message::WindowEvent(window::Event::CloseRequested) => {
-            state.show_confirm = true;
+        Message::WindowEvent(id, window::Event::Opened { .. }) => {
+            state.window_id = Some(id);
+            state.is_active = true;
+        }
+        Message::WindowEvent(_, window::Event::Focused) => {
+            state.is_active = true;
+        }
+        Message::WindowEvent(_, window::Event::Unfocused) => {
+            state.is_active = false;
         }
-        Message::WindowEvent(event) => {
-            // log the window event for debugging and forward it to the table handler
+        Message::WindowEvent(id, window::Event::CloseRequested) => {
+            state.is_active = false;
+            push_toast(
+                state,
+                toast::Toast::new(APP_NAME, "Minimized to the tray, still running", toast::Status::Info),
+            );
+            return window::change_mode(id, window::Mode::Hidden);
+        }
+        Message::WindowEvent(_, event) => {
             log::info!("WindowEvent: {event:?}");
-            state.main_table.on_window_event_debug(&format!("{event:?}"));
         }
-        Message::ConfirmExit => {
-            std::process::exit(0);
+        Message::CursorMoved(position) => {
+            state.main_table.on_cursor_moved(position);
         }
-        Message::TrayIconEvent(ref menu_id) => {
-            handle_tray_icon_event(menu_id);
+        Message::MousePressed(button) => {
+            // The table only occupies the Events tab, and sits below the tab bar we render above it.
+            if state.active_tab == 0 {
+                state.main_table.on_mouse_pressed(button, TAB_BAR_H);
+            }
+        }
+        Message::TrayIconEvent(ref menu_id) => match classify_tray_event(menu_id) {
+            Some(TrayAction::Show) => {
+                if state.is_active {
+                    log::info!("Show clicked, window already foregrounded");
+                } else {
+                    state.is_active = true;
+                    push_toast(state, toast::Toast::new(APP_NAME, "Window restored", toast::Status::Info));
+                    if let Some(id) = state.window_id {
+                        return Task::batch([
+                            window::change_mode(id, window::Mode::Windowed),
+                            window::gain_focus(id),
+                            window::minimize(id, false),
+                        ]);
+                    }
+                }
+            }
+            Some(TrayAction::Quit) => {
+                log::info!("Quit clicked");
+                TRAY_ICON_HANDLE.lock().unwrap().take();
+                std::process::exit(0);
+            }
+            None => {}
+        },
+        Message::TbMsg(data_table::TableMessage::ShowDetails(idx)) => {
+            if let Some((name, price)) = state.main_table.event_summary(idx) {
+                let status = if price > 0.0 { toast::Status::Info } else { toast::Status::Success };
+                let body = if price > 0.0 { format!("${price:.2}") } else { "Free entry".to_owned() };
+                push_toast(state, toast::Toast::new(name, body, status));
+            }
+            state.main_table.update(data_table::TableMessage::ShowDetails(idx));
         }
         Message::TbMsg(msg) => state.main_table.update(msg),
-        Message::CancelExit => {
-            state.show_confirm = false;
+        Message::PushToast(toast) => push_toast(state, toast),
+        Message::CloseToast(idx) => {
+            if idx < state.toasts.len() {
+                state.toasts.remove(idx);
+            }
+        }
+        Message::ExpireToasts => {
+            state.toasts.retain(|toast| !toast.is_expired(TOAST_TIMEOUT_SECS));
         }
+        Message::TabSelected(idx) => state.active_tab = idx,
+        Message::TabNext => state.active_tab = next_tab(state.active_tab),
+        Message::TabPrevious => state.active_tab = previous_tab(state.active_tab),
         Message::Noop => {}
     }
+    Task::none()
+}
+
+fn next_tab(current: usize) -> usize {
+    (current + 1) % TAB_LABELS.len()
+}
+
+fn previous_tab(current: usize) -> usize {
+    (current + TAB_LABELS.len() - 1) % TAB_LABELS.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_tab_wraps_around() {
+        assert_eq!(next_tab(0), 1);
+        assert_eq!(next_tab(TAB_LABELS.len() - 1), 0);
+    }
+
+    #[test]
+    fn previous_tab_wraps_around() {
+        assert_eq!(previous_tab(0), TAB_LABELS.len() - 1);
+        assert_eq!(previous_tab(1), 0);
+    }
 }
 
 fn view(state: &'_ AppState) -> iced::Element<'_, Message> {
-    let content: iced::Element<'_, Message> = if state.show_confirm {
-        container(column![
-            text("Are you sure you want to exit?"),
-            row![
-                button(text("Confirm")).on_press(Message::ConfirmExit),
-                button(text("Cancel")).on_press(Message::CancelExit)
-            ]
-        ])
-        .center_x(Length::Fill)
-        .center_y(Length::Fill)
-        .into()
-    } else {
-        // Use the data table view here and map its messages into our app Message::TbMsg
-        state.main_table.view().map(Message::TbMsg)
+    let tab_bar = tab_bar::view(&TAB_LABELS, state.active_tab, Message::TabSelected);
+
+    let tab_content: iced::Element<'_, Message> = match state.active_tab {
+        0 => state.main_table.view().map(Message::TbMsg),
+        1 => stats::view(state.main_table.stats()),
+        _ => state.main_table.settings_view().map(Message::TbMsg),
     };
-    content
+
+    let content = column![tab_bar, tab_content];
+    let toasts = toast::view(&state.toasts, Message::CloseToast);
+
+    stack![content, toasts].into()
 }
 
 const STR_SHOW: &str = "Show";
@@ -81,26 +201,32 @@ const STR_QUIT: &str = "Quit";
 static TRAY_ICON_MENU_ITEM_IDS: LazyLock<Arc<Mutex<HashMap<&str, tray_icon::menu::MenuId>>>> =
     LazyLock::new(|| Arc::new(Mutex::new(HashMap::new())));
 
-fn handle_tray_icon_event(event_id: &tray_icon::menu::MenuId) {
-    log::info!("Event ID: {event_id:?}");
-    let quit_id = TRAY_ICON_MENU_ITEM_IDS.lock().unwrap().get(&STR_QUIT).cloned();
-    let show_id = TRAY_ICON_MENU_ITEM_IDS.lock().unwrap().get(&STR_SHOW).cloned();
-    if let Some(show_id) = show_id
-        && event_id == &show_id
-    {
-        log::info!("Show clicked");
-        // Here you would typically send a message to your application to show or hide the window
-    }
-    if let Some(quit_id) = quit_id
-        && event_id == &quit_id
-    {
-        log::info!("Quit clicked");
-        std::process::exit(0);
-    }
+// Holds the tray icon so the panic hook can tear it down from whichever thread panics.
+static TRAY_ICON_HANDLE: LazyLock<Mutex<Option<tray_icon::TrayIcon>>> = LazyLock::new(|| Mutex::new(None));
+
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let location = info.location().map(|l| l.to_string()).unwrap_or_else(|| "unknown location".to_owned());
+        let payload = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "<non-string panic payload>".to_owned());
+
+        log::error!("panic at {location}: {payload}");
+
+        // Drop the tray icon so a crash doesn't leave an orphaned icon in the system tray.
+        TRAY_ICON_HANDLE.lock().unwrap().take();
+
+        default_hook(info);
+    }));
 }
 
 fn main() -> Result<(), BoxedError> {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug")).init();
+    install_panic_hook();
 
     let (tx, rx) = std::sync::mpsc::channel();
 
@@ -129,7 +255,8 @@ fn main() -> Result<(), BoxedError> {
         tooltip: Some(APP_NAME.to_string()),
         ..Default::default()
     };
-    let _tray_icon = tray_icon::TrayIcon::new(attrs)?;
+    let tray_icon = tray_icon::TrayIcon::new(attrs)?;
+    *TRAY_ICON_HANDLE.lock().unwrap() = Some(tray_icon);
     std::thread::spawn(move || {
         for event in tray_icon::menu::MenuEvent::receiver() {
             if let Err(e) = tx.send(event.id().clone()) {
@@ -145,13 +272,31 @@ fn main() -> Result<(), BoxedError> {
         })
         .subscription(move |_state| {
             iced::Subscription::batch(vec![
-                window::events().map(|(_id, event)| Message::WindowEvent(event)),
+                window::events().map(|(id, event)| Message::WindowEvent(id, event)),
+                iced::event::listen_with(|event, _status, _id| match event {
+                    iced::Event::Mouse(mouse::Event::CursorMoved { position }) => Some(Message::CursorMoved(position)),
+                    iced::Event::Mouse(mouse::Event::ButtonPressed(button)) => Some(Message::MousePressed(button)),
+                    _ => None,
+                }),
                 iced::time::every(std::time::Duration::from_millis(100)).map(move |_| {
                     match TRAY_ICON_EVENT_RECEIVER.lock().unwrap().as_ref().unwrap().try_recv() {
                         Ok(event_id) => Message::TrayIconEvent(event_id),
                         Err(_) => Message::Noop,
                     }
                 }),
+                iced::time::every(std::time::Duration::from_secs(1)).map(|_| Message::ExpireToasts),
+                // Alt+Left/Right, not bare arrows, so this doesn't hijack cursor movement while
+                // the filter text input (added alongside sorting/filtering) has focus.
+                keyboard::on_key_press(|key, modifiers| {
+                    if !modifiers.alt() {
+                        return None;
+                    }
+                    match key {
+                        keyboard::Key::Named(Named::ArrowRight) => Some(Message::TabNext),
+                        keyboard::Key::Named(Named::ArrowLeft) => Some(Message::TabPrevious),
+                        _ => None,
+                    }
+                }),
             ])
         })
         .theme(Theme::Dark)
@@ -0,0 +1,33 @@
+use crate::data_table::TableMessage;
+use iced::widget::{column, row, slider, text, tooltip};
+use iced::{Center, Element, Font};
+
+/// Render the padding/separator controls for the Settings tab.
+pub fn view<'a>(padding: (f32, f32), separator: (f32, f32)) -> Element<'a, TableMessage> {
+    let labeled_slider = |label, range: std::ops::RangeInclusive<f32>, (x, y), on_change: fn(f32, f32) -> TableMessage| {
+        row![
+            text(label).font(Font::MONOSPACE).size(14).width(100),
+            tooltip(
+                slider(range.clone(), x, move |x| on_change(x, y)),
+                text!("{x:.0}px").font(Font::MONOSPACE).size(10),
+                tooltip::Position::Left
+            ),
+            tooltip(
+                slider(range, y, move |y| on_change(x, y)),
+                text!("{y:.0}px").font(Font::MONOSPACE).size(10),
+                tooltip::Position::Right
+            ),
+        ]
+        .spacing(10)
+        .align_y(Center)
+    };
+
+    column![
+        labeled_slider("Padding", 0.0..=30.0, padding, TableMessage::PaddingChanged),
+        labeled_slider("Separator", 0.0..=5.0, separator, TableMessage::SeparatorChanged)
+    ]
+    .spacing(10)
+    .width(400)
+    .padding(20)
+    .into()
+}
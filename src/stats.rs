@@ -0,0 +1,16 @@
+use crate::data_table::Stats;
+use iced::Element;
+use iced::widget::{column, text};
+
+/// Render the Stats tab: totals computed from the table's events.
+pub fn view<'a, Message: 'a>(stats: Stats) -> Element<'a, Message> {
+    column![
+        text(format!("Events: {}", stats.count)),
+        text(format!("Average rating: {:.2}", stats.average_rating)),
+        text(format!("Total price: ${:.2}", stats.total_price)),
+        text(format!("Free: {}    Paid: {}", stats.free_count, stats.paid_count)),
+    ]
+    .spacing(10)
+    .padding(20)
+    .into()
+}
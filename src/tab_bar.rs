@@ -0,0 +1,14 @@
+use iced::Element;
+use iced::widget::{button, row, text};
+
+/// A row of labeled tab buttons, highlighting the active one.
+pub fn view<'a, Message: 'a + Clone>(labels: &[&'a str], active: usize, on_select: impl Fn(usize) -> Message + 'a) -> Element<'a, Message> {
+    let mut bar = row![].spacing(8).padding(8);
+
+    for (i, label) in labels.iter().enumerate() {
+        let style = if i == active { button::primary } else { button::secondary };
+        bar = bar.push(button(text(*label)).style(style).on_press(on_select(i)));
+    }
+
+    bar.into()
+}
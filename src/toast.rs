@@ -0,0 +1,77 @@
+use iced::widget::{button, column, container, row, text};
+use iced::{Center, Color, Element, Length, Theme};
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Info,
+    Success,
+    Danger,
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub title: String,
+    pub body: String,
+    pub status: Status,
+    pub created: Instant,
+}
+
+impl Toast {
+    pub fn new(title: impl Into<String>, body: impl Into<String>, status: Status) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            status,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self, timeout_secs: f32) -> bool {
+        self.created.elapsed().as_secs_f32() >= timeout_secs
+    }
+}
+
+fn style(status: Status) -> impl Fn(&Theme) -> container::Style {
+    move |_theme| {
+        let background = match status {
+            Status::Info => Color::from_rgb8(0x2f, 0x80, 0xed),
+            Status::Success => Color::from_rgb8(0x27, 0xae, 0x60),
+            Status::Danger => Color::from_rgb8(0xeb, 0x57, 0x57),
+        };
+        container::Style {
+            background: Some(background.into()),
+            text_color: Some(Color::WHITE),
+            ..container::Style::default()
+        }
+    }
+}
+
+/// Render the toast list as a column stacked in the bottom-right corner of the window.
+pub fn view<'a, Message: 'a + Clone>(toasts: &'a [Toast], on_close: impl Fn(usize) -> Message + 'a) -> Element<'a, Message> {
+    let mut list = column![].spacing(8);
+
+    for (i, toast) in toasts.iter().enumerate() {
+        let card = container(
+            row![
+                column![text(&toast.title).size(14), text(&toast.body).size(12)].spacing(2),
+                button(text("x").size(12)).on_press(on_close(i)),
+            ]
+            .spacing(10)
+            .align_y(Center),
+        )
+        .padding(10)
+        .width(260)
+        .style(style(toast.status));
+
+        list = list.push(card);
+    }
+
+    container(list)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(16)
+        .align_x(iced::alignment::Horizontal::Right)
+        .align_y(iced::alignment::Vertical::Bottom)
+        .into()
+}